@@ -0,0 +1,19 @@
+//! Compile check that the crate builds and is usable without `std`:
+//! ```text
+//! cargo build -p maths --example no_std --no-default-features --features libm
+//! ```
+#![no_std]
+
+use maths::prelude::*;
+
+/// Returns where `ray` hits the ground triangle, normalising its direction so
+/// the distance is in world units.
+pub fn ground_hit(ray: Ray) -> Option<Vector3> {
+    let ray = Ray::new(ray.origin, ray.direction.normal());
+    let t = ray.intersect_tri(
+        Vector3::new(-1.0, 0.0, -1.0),
+        Vector3::new(1.0, 0.0, -1.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    )?;
+    Some(ray.at(t))
+}
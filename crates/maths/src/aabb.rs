@@ -0,0 +1,106 @@
+use crate::Vector3;
+
+/// Axis-aligned bounding box spanning from `min` to `max`.
+///
+/// Both bounds are inclusive: points lying exactly on a face of the box are
+/// contained in it, and boxes that share a face intersect.
+/// ```
+/// # use ::maths::prelude::*;
+/// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+/// assert!(aabb.contains(Vector3::new(1.0, 0.5, 0.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Aabb {
+    /// A box containing no points. Expanding it by a point yields a box
+    /// containing only that point.
+    pub const EMPTY: Self = Self {
+        min: Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+        max: Vector3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn new(min: Vector3, max: Vector3) -> Self {
+        Self { min, max }
+    }
+    /// Returns the smallest box containing every point in `points`, or
+    /// [`Aabb::EMPTY`] if there are none.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let aabb = Aabb::from_points(&[
+    ///     Vector3::new(1.0, -2.0, 0.5),
+    ///     Vector3::new(-1.0, 3.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, -4.0),
+    /// ]);
+    /// assert_eq!(aabb.min, Vector3::new(-1.0, -2.0, -4.0));
+    /// assert_eq!(aabb.max, Vector3::new(1.0, 3.0, 0.5));
+    /// assert_eq!(Aabb::from_points(&[]), Aabb::EMPTY);
+    /// ```
+    #[must_use]
+    pub fn from_points(points: &[Vector3]) -> Self {
+        let mut aabb = Self::EMPTY;
+        for &p in points {
+            aabb.expand(p);
+        }
+        aabb
+    }
+    /// Returns true if `p` lies inside or on the surface of the box.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// assert!(aabb.contains(Vector3::new(0.5, 0.5, 0.5)));
+    /// assert!(aabb.contains(Vector3::new(1.0, 1.0, 1.0)));
+    /// assert!(!aabb.contains(Vector3::new(1.5, 0.5, 0.5)));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, p: Vector3) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x)
+            && (self.min.y..=self.max.y).contains(&p.y)
+            && (self.min.z..=self.max.z).contains(&p.z)
+    }
+    /// Returns true if the two boxes overlap or touch.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let a = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let b = Aabb::new(Vector3::new(1.0, 0.5, 0.5), Vector3::new(2.0, 2.0, 2.0));
+    /// let c = Aabb::new(Vector3::new(1.5, 0.0, 0.0), Vector3::new(2.0, 1.0, 1.0));
+    /// assert!(a.intersects(&b));
+    /// assert!(!a.intersects(&c));
+    /// ```
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+    /// Grows the box, if necessary, so that it contains `p`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let mut aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+    /// aabb.expand(Vector3::new(2.0, -1.0, 0.5));
+    /// assert_eq!(aabb.min, Vector3::new(0.0, -1.0, 0.0));
+    /// assert_eq!(aabb.max, Vector3::new(2.0, 1.0, 1.0));
+    /// ```
+    pub fn expand(&mut self, p: Vector3) {
+        self.min = self.min.min(p);
+        self.max = self.max.max(p);
+    }
+    /// Returns the point at the center of the box.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let aabb = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 4.0, 6.0));
+    /// assert_eq!(aabb.center(), Vector3::new(1.0, 2.0, 3.0));
+    /// ```
+    #[must_use]
+    pub fn center(&self) -> Vector3 {
+        self.min.midpoint(self.max)
+    }
+}
@@ -0,0 +1,65 @@
+//! Approximate equality with [`approx`], comparing each component in turn.
+//! ```
+//! # use ::maths::prelude::*;
+//! let a = Vector3::new(0.1, 0.2, 0.3) + 0.1;
+//! let b = Vector3::new(0.2, 0.3, 0.4);
+//! ::approx::assert_ulps_eq!(a, b);
+//! ::approx::assert_abs_diff_ne!(a, b * 2.0);
+//!
+//! let m = Matrix4([[0.1; 4]; 4]) * Matrix4([[3.0; 4]; 4]);
+//! ::approx::assert_abs_diff_eq!(m, Matrix4([[1.2; 4]; 4]), epsilon = 1e-6);
+//! ```
+
+use ::approx::{AbsDiffEq, UlpsEq};
+
+use crate::{Matrix4, Vector2, Vector3, Vector4};
+
+/// Implements `AbsDiffEq` and `UlpsEq` for a vector type by comparing each
+/// of its components.
+macro_rules! approx_impl {
+    ($name:ident { $($c:ident),+ }) => {
+        impl AbsDiffEq for $name {
+            type Epsilon = f32;
+            fn default_epsilon() -> Self::Epsilon {
+                f32::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $(self.$c.abs_diff_eq(&other.$c, epsilon))&&+
+            }
+        }
+        impl UlpsEq for $name {
+            fn default_max_ulps() -> u32 {
+                f32::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $(self.$c.ulps_eq(&other.$c, epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+approx_impl!(Vector2 { x, y });
+approx_impl!(Vector3 { x, y, z });
+approx_impl!(Vector4 { x, y, z, w });
+
+impl AbsDiffEq for Matrix4 {
+    type Epsilon = f32;
+    fn default_epsilon() -> Self::Epsilon {
+        f32::default_epsilon()
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0
+            .as_flattened()
+            .abs_diff_eq(other.0.as_flattened(), epsilon)
+    }
+}
+impl UlpsEq for Matrix4 {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0
+            .as_flattened()
+            .ulps_eq(other.0.as_flattened(), epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,29 @@
+//! [`bytemuck`] support for casting slices of vectors and matrices to and from
+//! plain bytes or floats without copying.
+//! ```
+//! # use ::maths::prelude::*;
+//! let vertices = [Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)];
+//! let floats: &[f32] = ::bytemuck::cast_slice(&vertices);
+//! assert_eq!(floats, [1.0, 2.0, 3.0, 4.0]);
+//!
+//! let bytes: &[u8] = ::bytemuck::cast_slice(&vertices);
+//! assert_eq!(bytes.len(), 16);
+//!
+//! let m: &Matrix4 = ::bytemuck::from_bytes(::bytemuck::bytes_of(&[[1.0f32; 4]; 4]));
+//! assert_eq!(*m, Matrix4([[1.0; 4]; 4]));
+//! ```
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::{Matrix4, Vector2, Vector3, Vector4};
+
+// SAFETY: Each type is `#[repr(C)]` and made only of `f32`s, so it has no
+// padding and every bit pattern, including all zeroes, is a valid value.
+unsafe impl Zeroable for Vector2 {}
+unsafe impl Pod for Vector2 {}
+unsafe impl Zeroable for Vector3 {}
+unsafe impl Pod for Vector3 {}
+unsafe impl Zeroable for Vector4 {}
+unsafe impl Pod for Vector4 {}
+unsafe impl Zeroable for Matrix4 {}
+unsafe impl Pod for Matrix4 {}
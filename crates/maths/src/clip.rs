@@ -0,0 +1,188 @@
+//! Clipping of primitives against rectangles and planes.
+
+use core::ops::{Add, Mul, Sub};
+
+use crate::{Vector2, Vector4};
+
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BELOW: u8 = 4;
+const ABOVE: u8 = 8;
+
+/// Clips the line segment from `a` to `b` to the rectangle
+/// `(min_x, min_y, max_x, max_y)` using the Cohen–Sutherland algorithm.
+///
+/// The rectangle is inclusive of its edges. Returns the visible part of the
+/// segment, or `None` if the segment lies entirely outside the rectangle.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::maths::clip::clip_line;
+/// let rect = (0.0, 0.0, 10.0, 10.0);
+///
+/// let inside = (Vector2::new(1.0, 1.0), Vector2::new(9.0, 5.0));
+/// assert_eq!(clip_line(inside.0, inside.1, rect), Some(inside));
+///
+/// let crossing = clip_line(Vector2::new(5.0, 5.0), Vector2::new(15.0, 5.0), rect);
+/// assert_eq!(crossing, Some((Vector2::new(5.0, 5.0), Vector2::new(10.0, 5.0))));
+///
+/// let outside = clip_line(Vector2::new(-5.0, -1.0), Vector2::new(5.0, -1.0), rect);
+/// assert_eq!(outside, None);
+/// ```
+#[must_use]
+pub fn clip_line(
+    mut a: Vector2,
+    mut b: Vector2,
+    rect: (f32, f32, f32, f32),
+) -> Option<(Vector2, Vector2)> {
+    let (min_x, min_y, max_x, max_y) = rect;
+    let outcode = |p: Vector2| {
+        let mut code = 0;
+        if p.x < min_x {
+            code |= LEFT;
+        } else if p.x > max_x {
+            code |= RIGHT;
+        }
+        if p.y < min_y {
+            code |= BELOW;
+        } else if p.y > max_y {
+            code |= ABOVE;
+        }
+        code
+    };
+
+    let (mut code_a, mut code_b) = (outcode(a), outcode(b));
+    loop {
+        if code_a | code_b == 0 {
+            return Some((a, b));
+        }
+        if code_a & code_b != 0 {
+            return None;
+        }
+        // Move whichever endpoint is outside onto the edge it is beyond.
+        let code = if code_a != 0 { code_a } else { code_b };
+        let d = b - a;
+        let p = if code & ABOVE != 0 {
+            Vector2::new(a.x + d.x * (max_y - a.y) / d.y, max_y)
+        } else if code & BELOW != 0 {
+            Vector2::new(a.x + d.x * (min_y - a.y) / d.y, min_y)
+        } else if code & RIGHT != 0 {
+            Vector2::new(max_x, a.y + d.y * (max_x - a.x) / d.x)
+        } else {
+            Vector2::new(min_x, a.y + d.y * (min_x - a.x) / d.x)
+        };
+        if code == code_a {
+            a = p;
+            code_a = outcode(a);
+        } else {
+            b = p;
+            code_b = outcode(b);
+        }
+    }
+}
+
+/// The near plane of the clip volume for clip-space positions, `z >= -w`.
+pub const NEAR_PLANE: Vector4 = Vector4::new(0.0, 0.0, 1.0, 1.0);
+
+/// Triangle vertex in clip space, along with the attributes to interpolate
+/// across the triangle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct ClipVertex<T> {
+    pub position: Vector4,
+    pub attributes: T,
+}
+
+impl<T> ClipVertex<T> {
+    #[inline]
+    #[must_use]
+    pub const fn new(position: Vector4, attributes: T) -> Self {
+        Self {
+            position,
+            attributes,
+        }
+    }
+}
+impl<T> ClipVertex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    /// Linearly interpolates the position and attributes towards `rhs`.
+    fn lerp(self, rhs: Self, t: f32) -> Self {
+        Self {
+            position: self.position.lerp(rhs.position, t),
+            attributes: self.attributes + (rhs.attributes - self.attributes) * t,
+        }
+    }
+}
+
+/// Clips a triangle to the half-space where `plane.dot(position) >= 0` using
+/// the Sutherland–Hodgman algorithm.
+///
+/// Vertices created on the plane have their attributes linearly interpolated
+/// in clip space. Returns no triangles if the triangle is entirely behind the
+/// plane, the triangle unchanged if it is entirely in front, or one or two
+/// triangles when it straddles the plane. Output triangles keep the winding
+/// of the input. Vertices lying exactly on the plane are kept, and no
+/// triangles are returned if they are the only part of the triangle not
+/// behind it.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::maths::clip::{clip_tri, ClipVertex, NEAR_PLANE};
+/// let vertex = |x, y, z, attribute| ClipVertex::new(Vector4::new(x, y, z, 1.0), attribute);
+///
+/// let front = [vertex(0.0, 0.0, 0.0, 0.0), vertex(1.0, 0.0, 0.0, 1.0), vertex(0.0, 1.0, 0.0, 2.0)];
+/// assert_eq!(clip_tri(front, NEAR_PLANE), [Some(front), None]);
+///
+/// let behind = [vertex(0.0, 0.0, -2.0, 0.0), vertex(1.0, 0.0, -2.0, 1.0), vertex(0.0, 1.0, -2.0, 2.0)];
+/// assert_eq!(clip_tri(behind, NEAR_PLANE), [None, None]);
+///
+/// let touching = [vertex(0.0, 0.0, -1.0, 0.0), vertex(1.0, 0.0, -2.0, 1.0), vertex(0.0, 1.0, -2.0, 2.0)];
+/// assert_eq!(clip_tri(touching, NEAR_PLANE), [None, None]);
+///
+/// let straddling = [vertex(0.0, 0.0, 0.0, 0.0), vertex(1.0, 0.0, 0.0, 1.0), vertex(0.0, 1.0, -3.0, 2.0)];
+/// let [Some(first), Some(second)] = clip_tri(straddling, NEAR_PLANE) else {
+///     panic!("expected two triangles");
+/// };
+/// assert_eq!(first, [straddling[0], straddling[1], second[1]]);
+/// assert_eq!(second[0], straddling[0]);
+/// ::approx::assert_ulps_eq!(
+///     second[1].position.as_array().as_slice(),
+///     [2.0 / 3.0, 1.0 / 3.0, -1.0, 1.0].as_slice()
+/// );
+/// ::approx::assert_ulps_eq!(second[1].attributes, 4.0 / 3.0);
+/// ::approx::assert_ulps_eq!(
+///     second[2].position.as_array().as_slice(),
+///     [0.0, 1.0 / 3.0, -1.0, 1.0].as_slice()
+/// );
+/// ::approx::assert_ulps_eq!(second[2].attributes, 2.0 / 3.0);
+/// ```
+#[must_use]
+pub fn clip_tri<T>(tri: [ClipVertex<T>; 3], plane: Vector4) -> [Option<[ClipVertex<T>; 3]>; 2]
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    let distance = tri.map(|v| plane.dot(v.position));
+    let mut polygon = [tri[0]; 4];
+    let mut len = 0;
+    for i in 0..3 {
+        let j = (i + 1) % 3;
+        if distance[i] >= 0.0 {
+            polygon[len] = tri[i];
+            len += 1;
+        }
+        // Only a strict change of side crosses the plane, so vertices on it
+        // are not duplicated.
+        if (distance[i] > 0.0 && distance[j] < 0.0) || (distance[i] < 0.0 && distance[j] > 0.0) {
+            let t = distance[i] / (distance[i] - distance[j]);
+            polygon[len] = tri[i].lerp(tri[j], t);
+            len += 1;
+        }
+    }
+    match len {
+        3 => [Some([polygon[0], polygon[1], polygon[2]]), None],
+        4 => [
+            Some([polygon[0], polygon[1], polygon[2]]),
+            Some([polygon[0], polygon[2], polygon[3]]),
+        ],
+        _ => [None, None],
+    }
+}
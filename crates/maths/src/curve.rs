@@ -0,0 +1,61 @@
+//! Bézier curve evaluation.
+//!
+//! Curves are evaluated with de Casteljau's algorithm and work for any type
+//! that can be linearly interpolated, such as [`Vector2`](crate::Vector2) and
+//! [`Vector3`](crate::Vector3). The curve runs from the first control point at
+//! `t = 0` to the last at `t = 1`; values of `t` outside `[0, 1]` extrapolate
+//! the curve.
+
+use core::ops::{Add, Mul, Sub};
+
+fn lerp<T>(a: T, b: T, t: f32) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    a + (b - a) * t
+}
+
+/// Evaluates the quadratic Bézier curve with control points `p0`, `p1` and
+/// `p2` at `t`.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::maths::curve::quadratic;
+/// let (p0, p1, p2) = (
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(1.0, 2.0),
+///     Vector2::new(2.0, 0.0),
+/// );
+/// assert_eq!(quadratic(p0, p1, p2, 0.0), p0);
+/// assert_eq!(quadratic(p0, p1, p2, 1.0), p2);
+/// assert_eq!(quadratic(p0, p1, p2, 0.5), Vector2::new(1.0, 1.0));
+/// ```
+#[must_use]
+pub fn quadratic<T>(p0: T, p1: T, p2: T, t: f32) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    lerp(lerp(p0, p1, t), lerp(p1, p2, t), t)
+}
+
+/// Evaluates the cubic Bézier curve with control points `p0`, `p1`, `p2` and
+/// `p3` at `t`.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::maths::curve::cubic;
+/// let (p0, p1, p2, p3) = (
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(0.0, 4.0, 0.0),
+///     Vector3::new(4.0, 4.0, 0.0),
+///     Vector3::new(4.0, 0.0, 0.0),
+/// );
+/// assert_eq!(cubic(p0, p1, p2, p3, 0.0), p0);
+/// assert_eq!(cubic(p0, p1, p2, p3, 1.0), p3);
+/// assert_eq!(cubic(p0, p1, p2, p3, 0.5), Vector3::new(2.0, 3.0, 0.0));
+/// ```
+#[must_use]
+pub fn cubic<T>(p0: T, p1: T, p2: T, p3: T, t: f32) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>,
+{
+    lerp(quadratic(p0, p1, p2, t), quadratic(p1, p2, p3, t), t)
+}
@@ -0,0 +1,33 @@
+//! Floating point functions that live in `std` rather than `core`.
+//!
+//! Without the `std` feature these fall back to [`libm`].
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("maths requires either the `std` or `libm` feature");
+
+/// Defines a unary function forwarding to the `f32` method of the same name,
+/// or to the given `libm` function without `std`.
+macro_rules! float_fn {
+    ($($name:ident => $libm:ident),+ $(,)?) => {$(
+        #[cfg(feature = "std")]
+        #[inline]
+        pub(crate) fn $name(x: f32) -> f32 {
+            x.$name()
+        }
+        #[cfg(not(feature = "std"))]
+        #[inline]
+        pub(crate) fn $name(x: f32) -> f32 {
+            libm::$libm(x)
+        }
+    )+};
+}
+
+float_fn!(
+    sqrt => sqrtf,
+    sin => sinf,
+    cos => cosf,
+    floor => floorf,
+    ceil => ceilf,
+    round => roundf,
+    acos => acosf,
+);
@@ -1,13 +1,44 @@
 //! # Mathematics Primitives
 //! Base primitives for vectors, matrices and more.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled, in which
+//! case the `libm` feature must be enabled to provide the floating point
+//! functions.
+#![cfg_attr(not(feature = "std"), no_std)]
 
+mod float;
+
+#[macro_use]
+mod vector;
 mod vector2;
 pub use vector2::Vector2;
 mod vector3;
 pub use vector3::Vector3;
 mod vector4;
 pub use vector4::Vector4;
+mod matrix4;
+pub use matrix4::Matrix4;
+mod tri;
+pub use tri::Tri;
+mod ray;
+pub use ray::Ray;
+mod aabb;
+pub use aabb::Aabb;
+pub mod clip;
+pub mod curve;
+pub mod segment;
+
+mod approx;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "serde")]
+mod serde;
 
 pub mod prelude {
-    pub use crate::{Vector2, Vector3, Vector4};
+    pub use crate::{Aabb, Matrix4, Ray, Tri, Vector2, Vector3, Vector4};
 }
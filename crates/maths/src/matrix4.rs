@@ -0,0 +1,586 @@
+use core::fmt::{self, Display, Write};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::float::{cos, sin};
+use crate::{Vector3, Vector4};
+
+/// 4x4 matrix stored in row-major order, so `m.0[row][column]`.
+///
+/// Vectors are treated as columns and multiplied on the right, `m * v`.
+/// ```
+/// # use ::maths::prelude::*;
+/// let m = Matrix4([
+///     [1.0, 2.0, 3.0, 4.0],
+///     [5.0, 6.0, 7.0, 8.0],
+///     [9.0, 10.0, 11.0, 12.0],
+///     [13.0, 14.0, 15.0, 16.0],
+/// ]);
+/// assert_eq!(m.0[1][2], 7.0);
+/// ```
+///
+/// The matrix is `#[repr(C)]`, laid out as its 16 elements row by row with no
+/// padding.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::core::mem::{align_of, size_of};
+/// assert_eq!(size_of::<Matrix4>(), 64);
+/// assert_eq!(align_of::<Matrix4>(), 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[repr(C)]
+pub struct Matrix4(pub [[f32; 4]; 4]);
+
+impl Matrix4 {
+    /// The identity matrix, which leaves vectors unchanged.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Matrix4::IDENTITY * Matrix4::IDENTITY, Matrix4::IDENTITY);
+    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(Matrix4::IDENTITY * v, v);
+    /// ```
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    /// Matrices with a determinant below this are treated as singular.
+    const EPSILON: f32 = 1e-6;
+
+    /// Returns [`Matrix4::IDENTITY`].
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Matrix4::identity(), Matrix4::IDENTITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self::IDENTITY
+    }
+    /// Returns the matrix with the given rows.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let rows = [Vector4::X, Vector4::Y * 2.0, Vector4::ONE, Vector4::W];
+    /// let m = Matrix4::from_rows(rows);
+    /// assert_eq!(m.row(2), rows[2]);
+    /// assert_eq!(m.0[1], [0.0, 2.0, 0.0, 0.0]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_rows([r0, r1, r2, r3]: [Vector4; 4]) -> Self {
+        Self([r0.as_array(), r1.as_array(), r2.as_array(), r3.as_array()])
+    }
+    /// Returns the matrix with the given columns, the transpose of
+    /// [`Matrix4::from_rows()`] with the same vectors.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let columns = [Vector4::X, Vector4::Y * 2.0, Vector4::ONE, Vector4::W];
+    /// let m = Matrix4::from_cols(columns);
+    /// for i in 0..4 {
+    ///     assert_eq!(m.col(i), columns[i]);
+    ///     assert_eq!(m.row(i), Matrix4::from_rows(columns).col(i));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_cols([c0, c1, c2, c3]: [Vector4; 4]) -> Self {
+        Self([
+            [c0.x, c1.x, c2.x, c3.x],
+            [c0.y, c1.y, c2.y, c3.y],
+            [c0.z, c1.z, c2.z, c3.z],
+            [c0.w, c1.w, c2.w, c3.w],
+        ])
+    }
+    /// Returns the row at `index`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Matrix4::IDENTITY.row(1), Vector4::Y);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `index` is 4 or more.
+    #[inline]
+    #[must_use]
+    pub const fn row(&self, index: usize) -> Vector4 {
+        Vector4::from_array(self.0[index])
+    }
+    /// Returns the column at `index`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(m.col(3), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `index` is 4 or more.
+    #[inline]
+    #[must_use]
+    pub const fn col(&self, index: usize) -> Vector4 {
+        let [r0, r1, r2, r3] = self.0;
+        Vector4::new(r0[index], r1[index], r2[index], r3[index])
+    }
+    /// Returns the matrix translating points by `v`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(m * Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    /// assert_eq!(m * Vector4::X, Vector4::X);
+    /// ```
+    #[must_use]
+    pub const fn from_translation(v: Vector3) -> Self {
+        Self([
+            [1.0, 0.0, 0.0, v.x],
+            [0.0, 1.0, 0.0, v.y],
+            [0.0, 0.0, 1.0, v.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    /// Returns the matrix scaling each axis by the matching component of `v`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(m * Vector4::ONE, Vector4::new(2.0, 3.0, 4.0, 1.0));
+    /// ```
+    #[must_use]
+    pub const fn from_scale(v: Vector3) -> Self {
+        Self([
+            [v.x, 0.0, 0.0, 0.0],
+            [0.0, v.y, 0.0, 0.0],
+            [0.0, 0.0, v.z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    /// Returns the matrix rotating by `radians` about the x axis,
+    /// counter-clockwise when looking down the axis towards the origin.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// use ::core::f32::consts::FRAC_PI_2;
+    /// let m = Matrix4::from_rotation_x(FRAC_PI_2);
+    /// ::approx::assert_ulps_eq!(m * Vector4::Y, Vector4::Z);
+    /// ::approx::assert_ulps_eq!(m * Vector4::Z, -Vector4::Y);
+    /// ```
+    #[must_use]
+    pub fn from_rotation_x(radians: f32) -> Self {
+        let (s, c) = (sin(radians), cos(radians));
+        Self([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, c, -s, 0.0],
+            [0.0, s, c, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    /// Returns the matrix rotating by `radians` about the y axis,
+    /// counter-clockwise when looking down the axis towards the origin.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// use ::core::f32::consts::FRAC_PI_2;
+    /// let m = Matrix4::from_rotation_y(FRAC_PI_2);
+    /// ::approx::assert_ulps_eq!(m * Vector4::Z, Vector4::X);
+    /// ::approx::assert_ulps_eq!(m * Vector4::X, -Vector4::Z);
+    /// ```
+    #[must_use]
+    pub fn from_rotation_y(radians: f32) -> Self {
+        let (s, c) = (sin(radians), cos(radians));
+        Self([
+            [c, 0.0, s, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-s, 0.0, c, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    /// Returns the matrix rotating by `radians` about the z axis,
+    /// counter-clockwise when looking down the axis towards the origin.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// use ::core::f32::consts::FRAC_PI_2;
+    /// let m = Matrix4::from_rotation_z(FRAC_PI_2);
+    /// ::approx::assert_ulps_eq!(m * Vector4::X, Vector4::Y);
+    /// ::approx::assert_ulps_eq!(m * Vector4::Y, -Vector4::X);
+    /// ```
+    #[must_use]
+    pub fn from_rotation_z(radians: f32) -> Self {
+        let (s, c) = (sin(radians), cos(radians));
+        Self([
+            [c, -s, 0.0, 0.0],
+            [s, c, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    /// Returns the orthographic projection mapping the box bounded by `left`,
+    /// `right`, `bottom`, `top`, `near` and `far` to normalised device
+    /// coordinates from -1 to 1 on each axis.
+    ///
+    /// The camera looks down the negative z axis, so `near` and `far` are
+    /// distances in front of it and map to a depth of -1 and 1 respectively.
+    /// This matches the clip volume of [`clip::NEAR_PLANE`](crate::clip::NEAR_PLANE).
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4::orthographic(0.0, 4.0, 0.0, 2.0, 1.0, 5.0);
+    /// for (x, ndc_x) in [(0.0, -1.0), (4.0, 1.0)] {
+    ///     for (y, ndc_y) in [(0.0, -1.0), (2.0, 1.0)] {
+    ///         for (z, ndc_z) in [(-1.0, -1.0), (-5.0, 1.0)] {
+    ///             assert_eq!(
+    ///                 m * Vector4::new(x, y, z, 1.0),
+    ///                 Vector4::new(ndc_x, ndc_y, ndc_z, 1.0)
+    ///             );
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+        Self([
+            [2.0 / width, 0.0, 0.0, -(right + left) / width],
+            [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+            [0.0, 0.0, -2.0 / depth, -(far + near) / depth],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    /// Returns the determinant of the matrix.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Matrix4::IDENTITY.determinant(), 1.0);
+    /// let m = Matrix4([
+    ///     [2.0, 0.0, 0.0, 1.0],
+    ///     [0.0, 3.0, 0.0, 2.0],
+    ///     [0.0, 0.0, 4.0, 3.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// assert_eq!(m.determinant(), 24.0);
+    /// assert_eq!(Matrix4([[1.0; 4]; 4]).determinant(), 0.0);
+    /// ```
+    #[must_use]
+    pub fn determinant(self) -> f32 {
+        let [b00, b01, b02, b03, b04, b05, b06, b07, b08, b09, b10, b11] = self.minors();
+        b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06
+    }
+    /// Returns the inverse of the matrix, such that `m * m.inverse()` is the
+    /// identity, or `None` if the matrix is singular.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Matrix4::IDENTITY.inverse(), Some(Matrix4::IDENTITY));
+    /// let m = Matrix4([
+    ///     [2.0, 0.0, 1.0, 1.0],
+    ///     [0.0, 3.0, 0.0, 2.0],
+    ///     [1.0, 0.0, 4.0, 3.0],
+    ///     [0.0, 1.0, 0.0, 1.0],
+    /// ]);
+    /// let inverse = m.inverse().unwrap();
+    /// ::approx::assert_abs_diff_eq!(m * inverse, Matrix4::IDENTITY, epsilon = 1e-6);
+    /// ::approx::assert_abs_diff_eq!(inverse * m, Matrix4::IDENTITY, epsilon = 1e-6);
+    /// assert_eq!(Matrix4([[1.0; 4]; 4]).inverse(), None);
+    /// ```
+    #[must_use]
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det.abs() < Self::EPSILON {
+            return None;
+        }
+        let [b00, b01, b02, b03, b04, b05, b06, b07, b08, b09, b10, b11] = self.minors();
+        let [[a00, a01, a02, a03], [a10, a11, a12, a13], [a20, a21, a22, a23], [a30, a31, a32, a33]] =
+            self.0;
+        let adjugate = Self([
+            [
+                a11 * b11 - a12 * b10 + a13 * b09,
+                a02 * b10 - a01 * b11 - a03 * b09,
+                a31 * b05 - a32 * b04 + a33 * b03,
+                a22 * b04 - a21 * b05 - a23 * b03,
+            ],
+            [
+                a12 * b08 - a10 * b11 - a13 * b07,
+                a00 * b11 - a02 * b08 + a03 * b07,
+                a32 * b02 - a30 * b05 - a33 * b01,
+                a20 * b05 - a22 * b02 + a23 * b01,
+            ],
+            [
+                a10 * b10 - a11 * b08 + a13 * b06,
+                a01 * b08 - a00 * b10 - a03 * b06,
+                a30 * b04 - a31 * b02 + a33 * b00,
+                a21 * b02 - a20 * b04 - a23 * b00,
+            ],
+            [
+                a11 * b07 - a10 * b09 - a12 * b06,
+                a00 * b09 - a01 * b07 + a02 * b06,
+                a31 * b01 - a30 * b03 - a32 * b00,
+                a20 * b03 - a21 * b01 + a22 * b00,
+            ],
+        ]);
+        let inv_det = 1.0 / det;
+        Some(Self(adjugate.0.map(|row| row.map(|e| e * inv_det))))
+    }
+    /// Returns each of `points` transformed as a point, with an implicit `w`
+    /// of 1 so that translation applies.
+    ///
+    /// The `w` component of each result is discarded, so projective matrices
+    /// need their perspective divide applied separately. Requires the `std`
+    /// feature; see [`Matrix4::transform_points_into()`] otherwise.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4([
+    ///     [2.0, 0.0, 0.0, 1.0],
+    ///     [0.0, 2.0, 0.0, 2.0],
+    ///     [0.0, 0.0, 2.0, 3.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// let points = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)];
+    /// assert_eq!(
+    ///     m.transform_points(&points),
+    ///     [Vector3::new(1.0, 2.0, 3.0), Vector3::new(3.0, 4.0, 5.0)]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn transform_points(&self, points: &[Vector3]) -> Vec<Vector3> {
+        points.iter().map(|&p| self.transform_point(p)).collect()
+    }
+    /// Transforms each of `points` in place. See
+    /// [`Matrix4::transform_points()`].
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4([
+    ///     [2.0, 0.0, 0.0, 1.0],
+    ///     [0.0, 2.0, 0.0, 2.0],
+    ///     [0.0, 0.0, 2.0, 3.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// let mut points = [Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)];
+    /// m.transform_points_into(&mut points);
+    /// assert_eq!(points, [Vector3::new(1.0, 2.0, 3.0), Vector3::new(3.0, 4.0, 5.0)]);
+    /// ```
+    pub fn transform_points_into(&self, points: &mut [Vector3]) {
+        for p in points {
+            *p = self.transform_point(*p);
+        }
+    }
+    /// Returns the determinants of every 2x2 submatrix taken from the top two
+    /// rows, followed by those taken from the bottom two rows, shared by
+    /// [`Matrix4::determinant()`] and [`Matrix4::inverse()`].
+    fn minors(&self) -> [f32; 12] {
+        let [[a00, a01, a02, a03], [a10, a11, a12, a13], [a20, a21, a22, a23], [a30, a31, a32, a33]] =
+            self.0;
+        [
+            a00 * a11 - a01 * a10,
+            a00 * a12 - a02 * a10,
+            a00 * a13 - a03 * a10,
+            a01 * a12 - a02 * a11,
+            a01 * a13 - a03 * a11,
+            a02 * a13 - a03 * a12,
+            a20 * a31 - a21 * a30,
+            a20 * a32 - a22 * a30,
+            a20 * a33 - a23 * a30,
+            a21 * a32 - a22 * a31,
+            a21 * a33 - a23 * a31,
+            a22 * a33 - a23 * a32,
+        ]
+    }
+    fn transform_point(&self, p: Vector3) -> Vector3 {
+        (*self * p.extend(1.0)).truncate()
+    }
+}
+
+impl Display for Matrix4 {
+    /// Formats the matrix as four rows with right-aligned columns. Precision
+    /// is applied to every element; without one, elements are written like
+    /// the components of a vector, such as `1.0`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4([
+    ///     [1.0, -2.0, 3.0, 4.0],
+    ///     [5.0, 6.0, 7.0, 8.0],
+    ///     [9.0, 10.0, 11.0, 12.0],
+    ///     [13.0, 14.0, -15.5, 16.0],
+    /// ]);
+    /// let s = format!("{m:.2}");
+    /// assert_eq!(s.lines().count(), 4);
+    /// assert_eq!(s.lines().next(), Some("[ 1.00, -2.00,   3.00,  4.00]"));
+    /// assert_eq!(s.lines().last(), Some("[13.00, 14.00, -15.50, 16.00]"));
+    ///
+    /// let s = format!("{m}");
+    /// assert_eq!(s.lines().next(), Some("[ 1.0, -2.0,   3.0,  4.0]"));
+    /// assert_eq!(s.lines().last(), Some("[13.0, 14.0, -15.5, 16.0]"));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let element = |e: f32, width: usize, f: &mut dyn Write| match precision {
+            Some(precision) => write!(f, "{e:>width$.precision$}"),
+            None => write!(f, "{e:>width$?}"),
+        };
+        let mut widths = [0; 4];
+        for row in self.0 {
+            for (width, e) in widths.iter_mut().zip(row) {
+                let mut counter = Counter(0);
+                element(e, 0, &mut counter)?;
+                *width = (*width).max(counter.0);
+            }
+        }
+        for (i, row) in self.0.into_iter().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+            f.write_char('[')?;
+            for (j, (e, width)) in row.into_iter().zip(widths).enumerate() {
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+                element(e, width, f)?;
+            }
+            f.write_char(']')?;
+        }
+        Ok(())
+    }
+}
+
+impl Add for Matrix4 {
+    type Output = Self;
+    /// Adds each element of `rhs` to the matching element of the matrix.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4([[1.0; 4]; 4]) + Matrix4([[2.0; 4]; 4]);
+    /// assert_eq!(m, Matrix4([[3.0; 4]; 4]));
+    /// ```
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+impl AddAssign for Matrix4 {
+    /// Adds each element of `rhs` to the matching element of the matrix.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let mut m = Matrix4([[1.0; 4]; 4]);
+    /// m += Matrix4([[2.0; 4]; 4]);
+    /// assert_eq!(m, Matrix4([[3.0; 4]; 4]));
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        for (row, rhs) in self.0.iter_mut().zip(rhs.0) {
+            for (e, rhs) in row.iter_mut().zip(rhs) {
+                *e += rhs;
+            }
+        }
+    }
+}
+impl Neg for Matrix4 {
+    type Output = Self;
+    /// Negates each element of the matrix.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(-Matrix4([[1.0; 4]; 4]), Matrix4([[-1.0; 4]; 4]));
+    /// ```
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|row| row.map(|e| -e)))
+    }
+}
+impl Sub for Matrix4 {
+    type Output = Self;
+    /// Subtracts each element of `rhs` from the matching element of the matrix.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4([[1.0; 4]; 4]) - Matrix4([[2.0; 4]; 4]);
+    /// assert_eq!(m, Matrix4([[-1.0; 4]; 4]));
+    /// ```
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+impl SubAssign for Matrix4 {
+    /// Subtracts each element of `rhs` from the matching element of the matrix.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let mut m = Matrix4([[1.0; 4]; 4]);
+    /// m -= Matrix4([[2.0; 4]; 4]);
+    /// assert_eq!(m, Matrix4([[-1.0; 4]; 4]));
+    /// ```
+    fn sub_assign(&mut self, rhs: Self) {
+        for (row, rhs) in self.0.iter_mut().zip(rhs.0) {
+            for (e, rhs) in row.iter_mut().zip(rhs) {
+                *e -= rhs;
+            }
+        }
+    }
+}
+impl Mul for Matrix4 {
+    type Output = Self;
+    /// Returns the matrix product `self * rhs`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let lhs = Matrix4([
+    ///     [1.0, 2.0, 3.0, 4.0],
+    ///     [5.0, 6.0, 7.0, 8.0],
+    ///     [9.0, 10.0, 11.0, 12.0],
+    ///     [13.0, 14.0, 15.0, 16.0],
+    /// ]);
+    /// let rhs = Matrix4([
+    ///     [17.0, 18.0, 19.0, 20.0],
+    ///     [21.0, 22.0, 23.0, 24.0],
+    ///     [25.0, 26.0, 27.0, 28.0],
+    ///     [29.0, 30.0, 31.0, 32.0],
+    /// ]);
+    /// assert_eq!(
+    ///     lhs * rhs,
+    ///     Matrix4([
+    ///         [250.0, 260.0, 270.0, 280.0],
+    ///         [618.0, 644.0, 670.0, 696.0],
+    ///         [986.0, 1028.0, 1070.0, 1112.0],
+    ///         [1354.0, 1412.0, 1470.0, 1528.0],
+    ///     ])
+    /// );
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(core::array::from_fn(|row| {
+            core::array::from_fn(|column| (0..4).map(|i| self.0[row][i] * rhs.0[i][column]).sum())
+        }))
+    }
+}
+impl MulAssign for Matrix4 {
+    /// Replaces the matrix with the matrix product `self * rhs`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let a = Matrix4([
+    ///     [1.0, 2.0, 3.0, 4.0],
+    ///     [5.0, 6.0, 7.0, 8.0],
+    ///     [9.0, 10.0, 11.0, 12.0],
+    ///     [13.0, 14.0, 15.0, 16.0],
+    /// ]);
+    /// let mut m = a;
+    /// m *= Matrix4::IDENTITY;
+    /// assert_eq!(m, a);
+    /// ```
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl Mul<Vector4> for Matrix4 {
+    type Output = Vector4;
+    /// Transforms the column vector `v`, returning `self * v`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let m = Matrix4([
+    ///     [1.0, 0.0, 0.0, 5.0],
+    ///     [0.0, 2.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 3.0, 0.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// assert_eq!(
+    ///     m * Vector4::new(1.0, 1.0, 1.0, 1.0),
+    ///     Vector4::new(6.0, 2.0, 3.0, 1.0)
+    /// );
+    /// ```
+    fn mul(self, v: Vector4) -> Self::Output {
+        let [x, y, z, w] = self.0.map(|row| Vector4::from_array(row).dot(v));
+        Vector4 { x, y, z, w }
+    }
+}
+
+/// Counts the bytes written to it, used to measure formatted output.
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
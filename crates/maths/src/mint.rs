@@ -0,0 +1,48 @@
+//! Conversions to and from the [`mint`] interoperability types.
+//! ```
+//! # use ::maths::prelude::*;
+//! let v = Vector3::new(1.0, 2.0, 3.0);
+//! let m: ::mint::Vector3<f32> = v.into();
+//! assert_eq!(Vector3::from(m), v);
+//!
+//! let p: ::mint::Point2<f32> = Vector2::new(1.0, 2.0).into();
+//! assert_eq!(Vector2::from(p), Vector2::new(1.0, 2.0));
+//!
+//! let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+//! assert_eq!(Vector4::from(::mint::Vector4::from(v)), v);
+//! ```
+
+use crate::{Vector2, Vector3, Vector4};
+
+/// Implements `From` in both directions between a vector type and a `mint`
+/// type with the same components.
+macro_rules! mint_impl {
+    ($name:ident, $mint:ident { $($c:ident),+ }) => {
+        impl From<$name> for ::mint::$mint<f32> {
+            fn from(value: $name) -> Self {
+                Self { $($c: value.$c),+ }
+            }
+        }
+        impl From<::mint::$mint<f32>> for $name {
+            fn from(value: ::mint::$mint<f32>) -> Self {
+                Self { $($c: value.$c),+ }
+            }
+        }
+    };
+}
+
+mint_impl!(Vector2, Vector2 { x, y });
+mint_impl!(Vector2, Point2 { x, y });
+mint_impl!(Vector3, Vector3 { x, y, z });
+mint_impl!(Vector3, Point3 { x, y, z });
+mint_impl!(Vector4, Vector4 { x, y, z, w });
+
+impl ::mint::IntoMint for Vector2 {
+    type MintType = ::mint::Vector2<f32>;
+}
+impl ::mint::IntoMint for Vector3 {
+    type MintType = ::mint::Vector3<f32>;
+}
+impl ::mint::IntoMint for Vector4 {
+    type MintType = ::mint::Vector4<f32>;
+}
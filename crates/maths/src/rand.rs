@@ -0,0 +1,79 @@
+//! Random vector generation with [`rand`].
+//!
+//! Sampling a vector from [`StandardUniform`] gives each component uniformly
+//! in `[0, 1)`.
+//! ```
+//! # use ::maths::prelude::*;
+//! use ::rand::{rngs::StdRng, Rng, SeedableRng};
+//! let mut rng = StdRng::seed_from_u64(0);
+//! let v: Vector4 = rng.random();
+//! assert!(v.as_array().iter().all(|c| (0.0..1.0).contains(c)));
+//! ```
+
+use ::rand::{
+    distr::{Distribution, StandardUniform},
+    Rng,
+};
+use core::f32::consts::TAU;
+
+use crate::{
+    float::{cos, sin, sqrt},
+    Vector2, Vector3, Vector4,
+};
+
+impl Distribution<Vector2> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        Vector2::from_array(rng.random())
+    }
+}
+impl Distribution<Vector3> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        Vector3::from_array(rng.random())
+    }
+}
+impl Distribution<Vector4> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector4 {
+        Vector4::from_array(rng.random())
+    }
+}
+
+impl Vector2 {
+    /// Returns a unit vector pointing in a uniformly distributed direction.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// use ::rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let v = Vector2::random_unit(&mut rng);
+    /// ::approx::assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1e-6);
+    /// ```
+    #[must_use]
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_angle(rng.random_range(0.0..TAU))
+    }
+}
+impl Vector3 {
+    /// Returns a unit vector pointing in a direction uniformly distributed
+    /// over the sphere.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// use ::rand::{rngs::StdRng, SeedableRng};
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let samples: Vec<_> = (0..10_000).map(|_| Vector3::random_unit(&mut rng)).collect();
+    /// for v in &samples {
+    ///     ::approx::assert_relative_eq!(v.magnitude(), 1.0, epsilon = 1e-6);
+    /// }
+    /// let mean = samples.iter().sum::<Vector3>() / 10_000.0;
+    /// assert!(mean.magnitude() < 0.05);
+    /// ```
+    #[must_use]
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let z: f32 = rng.random_range(-1.0..=1.0);
+        let r = sqrt(1.0 - z * z);
+        let theta = rng.random_range(0.0..TAU);
+        Self {
+            x: r * cos(theta),
+            y: r * sin(theta),
+            z,
+        }
+    }
+}
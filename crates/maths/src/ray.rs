@@ -0,0 +1,119 @@
+use crate::{Aabb, Vector3};
+
+/// Half-line starting at `origin` and extending along `direction`.
+/// ```
+/// # use ::maths::prelude::*;
+/// let ray = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+/// assert_eq!(ray.at(2.0), Vector3::new(0.0, 0.0, 2.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub direction: Vector3,
+}
+
+impl Ray {
+    /// Intersections closer than this distance are ignored, so rays cast from
+    /// a surface do not hit it again.
+    const EPSILON: f32 = 1e-6;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(origin: Vector3, direction: Vector3) -> Self {
+        Self { origin, direction }
+    }
+    /// Returns the point at distance `t` along the ray, measured in multiples
+    /// of the direction vector.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let ray = Ray::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(ray.at(0.5), Vector3::new(1.5, 2.0, 3.0));
+    /// ```
+    #[must_use]
+    pub fn at(self, t: f32) -> Vector3 {
+        self.origin + self.direction * t
+    }
+    /// Returns the distance `t` along the ray to its intersection with the
+    /// triangle `a`, `b`, `c`, using the Möller–Trumbore algorithm.
+    ///
+    /// Triangles are two-sided: a hit is reported whether the ray strikes the
+    /// front or the back face. Rays parallel to the triangle never hit it.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let (a, b, c) = (
+    ///     Vector3::new(-1.0, -1.0, 1.0),
+    ///     Vector3::new(1.0, -1.0, 1.0),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    /// );
+    /// let hit = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    /// ::approx::assert_ulps_eq!(hit.intersect_tri(a, b, c).unwrap(), 1.0);
+    ///
+    /// let miss = Ray::new(Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    /// assert_eq!(miss.intersect_tri(a, b, c), None);
+    /// ```
+    #[must_use]
+    pub fn intersect_tri(&self, a: Vector3, b: Vector3, c: Vector3) -> Option<f32> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let h = self.direction.cross(edge2);
+        let det = edge1.dot(h);
+        if det.abs() < Self::EPSILON {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        let s = self.origin - a;
+        let u = inv_det * s.dot(h);
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = s.cross(edge1);
+        let v = inv_det * self.direction.dot(q);
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = inv_det * edge2.dot(q);
+        (t > Self::EPSILON).then_some(t)
+    }
+    /// Returns the distance `t` along the ray to the point where it enters the
+    /// box, using the slab method.
+    ///
+    /// A ray starting inside the box hits it at `t = 0`. An empty box, such as
+    /// [`Aabb::EMPTY`], is never hit.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let aabb = Aabb::from_points(&[
+    ///     Vector3::new(-1.0, -1.0, 2.0),
+    ///     Vector3::new(1.0, 1.0, 4.0),
+    ///     Vector3::new(0.0, 0.5, 3.0),
+    /// ]);
+    /// let through = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+    /// ::approx::assert_ulps_eq!(through.intersect_aabb(&aabb).unwrap(), 2.0);
+    ///
+    /// let away = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(away.intersect_aabb(&aabb), None);
+    ///
+    /// let inside = Ray::new(aabb.center(), Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(inside.intersect_aabb(&aabb), Some(0.0));
+    ///
+    /// assert_eq!(through.intersect_aabb(&Aabb::EMPTY), None);
+    /// assert_eq!(through.intersect_aabb(&Aabb::from_points(&[])), None);
+    /// ```
+    #[must_use]
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        let mut near = 0.0f32;
+        let mut far = f32::INFINITY;
+        let slabs = (self.origin.as_array().into_iter())
+            .zip(self.direction.as_array())
+            .zip(aabb.min.as_array().into_iter().zip(aabb.max.as_array()));
+        for ((origin, direction), (min, max)) in slabs {
+            if min > max {
+                return None;
+            }
+            let inv = 1.0 / direction;
+            let (t0, t1) = ((min - origin) * inv, (max - origin) * inv);
+            near = near.max(t0.min(t1));
+            far = far.min(t0.max(t1));
+        }
+        (near <= far).then_some(near)
+    }
+}
@@ -0,0 +1,41 @@
+//! Queries against 2-dimensional line segments.
+
+use crate::Vector2;
+
+/// Returns the point on the segment from `a` to `b` closest to `p`.
+///
+/// If the segment is degenerate (`a == b`) the result is `a`.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::maths::segment::closest_point_on_segment;
+/// let (a, b) = (Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0));
+/// assert_eq!(closest_point_on_segment(Vector2::new(2.0, 3.0), a, b), Vector2::new(2.0, 0.0));
+/// assert_eq!(closest_point_on_segment(Vector2::new(6.0, 1.0), a, b), b);
+/// assert_eq!(closest_point_on_segment(Vector2::new(6.0, 1.0), a, a), a);
+/// ```
+#[must_use]
+pub fn closest_point_on_segment(p: Vector2, a: Vector2, b: Vector2) -> Vector2 {
+    let ab = b - a;
+    let length_squared = ab.dot(ab);
+    if length_squared == 0.0 {
+        return a;
+    }
+    let t = ((p - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+/// Returns the distance from `p` to the closest point on the segment from `a`
+/// to `b`.
+///
+/// If the segment is degenerate (`a == b`) this is the distance to `a`.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::maths::segment::distance_to_segment;
+/// let (a, b) = (Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0));
+/// ::approx::assert_ulps_eq!(distance_to_segment(Vector2::new(2.0, 3.0), a, b), 3.0);
+/// ::approx::assert_ulps_eq!(distance_to_segment(Vector2::new(7.0, 4.0), a, b), 5.0);
+/// ```
+#[must_use]
+pub fn distance_to_segment(p: Vector2, a: Vector2, b: Vector2) -> f32 {
+    p.distance(closest_point_on_segment(p, a, b))
+}
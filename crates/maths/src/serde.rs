@@ -0,0 +1,30 @@
+//! [`serde`] support, derived on each type.
+//!
+//! Vectors serialize as structs with named components and
+//! [`Matrix4`](crate::Matrix4) as a nested array of its rows.
+//! ```
+//! # use ::maths::prelude::*;
+//! let v = Vector2::new(1.0, 2.0);
+//! let json = ::serde_json::to_string(&v).unwrap();
+//! assert_eq!(json, r#"{"x":1.0,"y":2.0}"#);
+//! assert_eq!(::serde_json::from_str::<Vector2>(&json).unwrap(), v);
+//!
+//! let v = Vector3::new(1.0, 2.0, 3.0);
+//! assert_eq!(::serde_json::from_value::<Vector3>(::serde_json::to_value(v).unwrap()).unwrap(), v);
+//!
+//! let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+//! assert_eq!(::serde_json::from_value::<Vector4>(::serde_json::to_value(v).unwrap()).unwrap(), v);
+//!
+//! let m = Matrix4([
+//!     [1.0, 0.0, 0.0, 2.0],
+//!     [0.0, 1.0, 0.0, 3.0],
+//!     [0.0, 0.0, 1.0, 4.0],
+//!     [0.0, 0.0, 0.0, 1.0],
+//! ]);
+//! let json = ::serde_json::to_string(&m).unwrap();
+//! assert_eq!(
+//!     json,
+//!     "[[1.0,0.0,0.0,2.0],[0.0,1.0,0.0,3.0],[0.0,0.0,1.0,4.0],[0.0,0.0,0.0,1.0]]"
+//! );
+//! assert_eq!(::serde_json::from_str::<Matrix4>(&json).unwrap(), m);
+//! ```
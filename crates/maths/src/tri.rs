@@ -0,0 +1,308 @@
+use core::ops::{Add, Index, IndexMut, Mul};
+
+use crate::{Vector2, Vector3};
+
+/// Triangle in screen space.
+///
+/// The `x` and `y` components of each vertex are its position on screen and
+/// `z` is its depth. Planar queries such as [`Tri::contains()`] only consider
+/// `x` and `y`.
+/// ```
+/// # use ::maths::prelude::*;
+/// let tri = Tri::new(
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(1.0, 0.0, 0.0),
+///     Vector3::new(0.0, 1.0, 0.0),
+/// );
+/// assert_eq!(tri[1], Vector3::new(1.0, 0.0, 0.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Tri(pub [Vector3; 3]);
+
+impl Tri {
+    /// Triangles whose area, doubled, is below this are treated as degenerate.
+    const EPSILON: f32 = 1e-6;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        Self([a, b, c])
+    }
+    /// Returns the barycentric weights of the point `(x, y)` with respect to
+    /// each vertex of the triangle.
+    ///
+    /// Inside the triangle every weight lies in `[0, 1]` and the weights sum
+    /// to 1. Outside the triangle at least one weight is negative.
+    ///
+    /// The weights are normalised by the area of the triangle, so `None` is
+    /// returned for a degenerate triangle whose area is close to zero, such as
+    /// one with collinear vertices.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(3.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 3.0, 0.0),
+    /// );
+    /// let centroid = tri.barycentric(1.0, 1.0).unwrap();
+    /// ::approx::assert_ulps_eq!(
+    ///     centroid.as_array().as_slice(),
+    ///     [1.0 / 3.0; 3].as_slice()
+    /// );
+    /// let vertex = tri.barycentric(0.0, 0.0).unwrap();
+    /// ::approx::assert_ulps_eq!(
+    ///     vertex.as_array().as_slice(),
+    ///     [1.0, 0.0, 0.0].as_slice()
+    /// );
+    ///
+    /// let collinear = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 1.0, 0.0),
+    ///     Vector3::new(2.0, 2.0, 0.0),
+    /// );
+    /// assert_eq!(collinear.barycentric(1.0, 1.0), None);
+    /// ```
+    #[must_use]
+    pub fn barycentric(&self, x: f32, y: f32) -> Option<Vector3> {
+        let [a, b, c] = self.0;
+        let p = Vector3::new(x, y, 0.0);
+        let area = edge(a, b, c);
+        if area.abs() < Self::EPSILON {
+            return None;
+        }
+        Some(Vector3 {
+            x: edge(b, c, p) / area,
+            y: edge(c, a, p) / area,
+            z: edge(a, b, p) / area,
+        })
+    }
+    /// Interpolates the per-vertex `values` at the point `(x, y)` using the
+    /// barycentric weights of the point.
+    ///
+    /// Returns `None` for a degenerate triangle, see [`Tri::barycentric()`].
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    /// let u = [0.0, 1.0, 0.0];
+    /// ::approx::assert_ulps_eq!(tri.interpolate(1.0, 0.0, u).unwrap(), 0.5);
+    /// ::approx::assert_ulps_eq!(tri.interpolate(2.0, 0.0, u).unwrap(), 1.0);
+    /// ```
+    #[must_use]
+    pub fn interpolate<T>(&self, x: f32, y: f32, [a, b, c]: [T; 3]) -> Option<T>
+    where
+        T: Add<Output = T> + Mul<f32, Output = T>,
+    {
+        let w = self.barycentric(x, y)?;
+        Some(a * w.x + b * w.y + c * w.z)
+    }
+    /// Interpolates the depth of the vertices at the point `(x, y)`.
+    ///
+    /// Depth is interpolated linearly in screen space, which is correct for
+    /// depths that have already been through the perspective divide. Returns
+    /// `None` for a degenerate triangle, see [`Tri::barycentric()`].
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(3.0, 0.0, 1.0),
+    ///     Vector3::new(0.0, 3.0, 2.0),
+    /// );
+    /// let centroid = tri.centroid();
+    /// ::approx::assert_ulps_eq!(tri.interpolate_depth(centroid.x, centroid.y).unwrap(), 1.0);
+    /// ```
+    #[must_use]
+    pub fn interpolate_depth(&self, x: f32, y: f32) -> Option<f32> {
+        let [a, b, c] = self.0;
+        self.interpolate(x, y, [a.z, b.z, c.z])
+    }
+    /// Returns the change in `x` per unit change in `y` along the edge from
+    /// vertex `from` to vertex `to`.
+    ///
+    /// This is the step a scanline rasteriser takes along an edge for each
+    /// row. Horizontal edges have an infinite inverse gradient.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(4.0, 2.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    /// ::approx::assert_ulps_eq!(tri.inverse_gradient(0, 1), 2.0);
+    /// ::approx::assert_ulps_eq!(tri.inverse_gradient(0, 2), 0.0);
+    /// assert!(tri.inverse_gradient(1, 2).is_infinite());
+    /// ```
+    #[must_use]
+    pub fn inverse_gradient(&self, from: usize, to: usize) -> f32 {
+        let (a, b) = (self[from], self[to]);
+        (b.x - a.x) / (b.y - a.y)
+    }
+    /// Returns the area of the triangle.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// ::approx::assert_ulps_eq!(tri.area(), 0.5);
+    /// ```
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+    /// Returns the area of the triangle, positive when the vertices wind
+    /// counter-clockwise and negative when they wind clockwise.
+    ///
+    /// Winding is measured with the y axis pointing up. In screen space, where
+    /// y points down, the sign is reversed relative to how the triangle
+    /// appears on screen.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let a = Vector3::new(0.0, 0.0, 0.0);
+    /// let b = Vector3::new(1.0, 0.0, 0.0);
+    /// let c = Vector3::new(0.0, 1.0, 0.0);
+    /// ::approx::assert_ulps_eq!(Tri::new(a, b, c).signed_area(), 0.5);
+    /// ::approx::assert_ulps_eq!(Tri::new(a, c, b).signed_area(), -0.5);
+    /// ```
+    #[must_use]
+    pub fn signed_area(&self) -> f32 {
+        let [a, b, c] = self.0;
+        edge(a, b, c) / 2.0
+    }
+    /// Returns true if the vertices wind clockwise, measured with the y axis
+    /// pointing up. See [`Tri::signed_area()`].
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let a = Vector3::new(0.0, 0.0, 0.0);
+    /// let b = Vector3::new(1.0, 0.0, 0.0);
+    /// let c = Vector3::new(0.0, 1.0, 0.0);
+    /// assert!(!Tri::new(a, b, c).is_clockwise());
+    /// assert!(Tri::new(a, c, b).is_clockwise());
+    /// ```
+    #[must_use]
+    pub fn is_clockwise(&self) -> bool {
+        self.signed_area() < 0.0
+    }
+    /// Returns the unit normal of the plane the triangle lies in.
+    ///
+    /// The normal follows the right-hand rule: it points towards a viewer who
+    /// sees the vertices winding counter-clockwise. Returns `None` for a
+    /// degenerate triangle, which has no single plane.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let a = Vector3::new(0.0, 0.0, 0.0);
+    /// let b = Vector3::new(1.0, 0.0, 0.0);
+    /// let c = Vector3::new(0.0, 1.0, 0.0);
+    /// assert_eq!(Tri::new(a, b, c).face_normal(), Some(Vector3::new(0.0, 0.0, 1.0)));
+    /// assert_eq!(Tri::new(a, c, b).face_normal(), Some(Vector3::new(0.0, 0.0, -1.0)));
+    ///
+    /// let collinear = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 1.0, 1.0),
+    ///     Vector3::new(2.0, 2.0, 2.0),
+    /// );
+    /// assert_eq!(collinear.face_normal(), None);
+    /// ```
+    #[must_use]
+    pub fn face_normal(&self) -> Option<Vector3> {
+        let [a, b, c] = self.0;
+        let n = (b - a).cross(c - a);
+        let m = n.magnitude();
+        (m >= Self::EPSILON).then(|| n / m)
+    }
+    /// Returns the centroid of the triangle, the average of its vertices.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// ::approx::assert_ulps_eq!(
+    ///     tri.centroid().as_array().as_slice(),
+    ///     [1.0 / 3.0, 1.0 / 3.0, 0.0].as_slice()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn centroid(&self) -> Vector3 {
+        let [a, b, c] = self.0;
+        (a + b + c) / 3.0
+    }
+    /// Returns true if the point `p` lies inside or on an edge of the
+    /// triangle.
+    ///
+    /// The test checks that `p` is on the same side of all three edges, so it
+    /// gives the same result for clockwise and counter-clockwise triangles.
+    /// Degenerate triangles, whose area is close to zero, contain no points.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert!(tri.contains(Vector2::new(0.25, 0.25)));
+    /// assert!(!tri.contains(Vector2::new(1.0, 1.0)));
+    ///
+    /// let [a, b, c] = tri.0;
+    /// assert!(Tri::new(c, b, a).contains(Vector2::new(0.25, 0.25)));
+    ///
+    /// let point = Tri::new(a, a, a);
+    /// assert!(!point.contains(Vector2::new(100.0, -50.0)));
+    /// let collinear = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 1.0, 0.0),
+    ///     Vector3::new(2.0, 2.0, 0.0),
+    /// );
+    /// assert!(!collinear.contains(Vector2::new(5.0, 5.0)));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, p: Vector2) -> bool {
+        let [a, b, c] = self.0;
+        if edge(a, b, c).abs() < Self::EPSILON {
+            return false;
+        }
+        let p = Vector3::new(p.x, p.y, 0.0);
+        let edges = [edge(a, b, p), edge(b, c, p), edge(c, a, p)];
+        edges.iter().all(|&e| e >= 0.0) || edges.iter().all(|&e| e <= 0.0)
+    }
+}
+
+impl Index<usize> for Tri {
+    type Output = Vector3;
+    /// Returns the vertex at `index`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let tri = Tri::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// assert_eq!(tri[2], Vector3::new(0.0, 1.0, 0.0));
+    /// ```
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+impl IndexMut<usize> for Tri {
+    /// Returns a mutable reference to the vertex at `index`.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let mut tri = Tri::default();
+    /// tri[1] = Vector3::new(1.0, 0.0, 0.0);
+    /// assert_eq!(tri.0[1], Vector3::new(1.0, 0.0, 0.0));
+    /// ```
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p` projected onto the xy
+/// plane.
+fn edge(a: Vector3, b: Vector3, p: Vector3) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
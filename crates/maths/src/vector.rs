@@ -0,0 +1,625 @@
+/// Implements the component-wise operations shared by every vector type.
+///
+/// Takes the vector type, its component fields in order, each paired with
+/// the name of its unit axis constant, and the number of components.
+macro_rules! vector_impl {
+    ($name:ident { $($c:ident: $axis:ident),+ }, $n:literal) => {
+        impl $name {
+            #[doc = "The vector with every component set to 0."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::ZERO, ", stringify!($name), "::from_array([0.0; ", $n, "]));")]
+            #[doc = "```"]
+            pub const ZERO: Self = Self::splat(0.0);
+            #[doc = "The vector with every component set to 1."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::ONE, ", stringify!($name), "::from_array([1.0; ", $n, "]));")]
+            #[doc = "```"]
+            pub const ONE: Self = Self::splat(1.0);
+
+            $(
+                #[doc = concat!("The unit vector along the `", stringify!($c), "` axis.")]
+                #[doc = "```"]
+                #[doc = "# use ::maths::prelude::*;"]
+                #[doc = concat!("assert_eq!(", stringify!($name), "::", stringify!($axis), ", ", stringify!($name), "::ZERO.with_", stringify!($c), "(1.0));")]
+                #[doc = "```"]
+                pub const $axis: Self = Self {
+                    $c: 1.0,
+                    ..Self::ZERO
+                };
+            )+
+
+            #[doc = "Returns a vector with every component set to `v`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::splat(2.0), ", stringify!($name), "::from_array([2.0; ", $n, "]));")]
+            #[doc = "```"]
+            #[inline]
+            #[must_use]
+            pub const fn splat(v: f32) -> Self {
+                Self {
+                    $($c: v),+
+                }
+            }
+            #[doc = "Returns the point halfway between the vector and `other`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0.0; ", $n, "]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([2.0; ", $n, "]);")]
+            #[doc = concat!("assert_eq!(a.midpoint(b), ", stringify!($name), "::from_array([1.0; ", $n, "]));")]
+            #[doc = "```"]
+            #[inline]
+            #[must_use]
+            pub const fn midpoint(self, other: Self) -> Self {
+                Self {
+                    $($c: (self.$c + other.$c) * 0.5),+
+                }
+            }
+            #[doc = "Returns the squared magnitude of the vector, avoiding the square root of [`Self::magnitude()`]."]
+            #[doc = ""]
+            #[doc = "Useful for comparing lengths, since squaring preserves their order."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array([0.0; ", $n, "]).with_x(3.0).with_y(4.0);")]
+            #[doc = "assert_eq!(v.magnitude_squared(), 25.0);"]
+            #[doc = "```"]
+            #[inline]
+            #[must_use]
+            pub fn magnitude_squared(self) -> f32 {
+                self.dot(self)
+            }
+            #[doc = "Returns the distance between the vector and `rhs` treated as points."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0.0; ", $n, "]);")]
+            #[doc = "let b = a.with_x(3.0).with_y(4.0);"]
+            #[doc = "::approx::assert_ulps_eq!(a.distance(b), 5.0);"]
+            #[doc = "```"]
+            #[must_use]
+            pub fn distance(self, rhs: Self) -> f32 {
+                (self - rhs).magnitude()
+            }
+            #[doc = "Returns the squared distance between the vector and `rhs` treated as points, avoiding the square root of [`Self::distance()`]."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0.0; ", $n, "]);")]
+            #[doc = "let b = a.with_x(3.0).with_y(4.0);"]
+            #[doc = "assert_eq!(a.distance_squared(b), 25.0);"]
+            #[doc = "```"]
+            #[inline]
+            #[must_use]
+            pub fn distance_squared(self, rhs: Self) -> f32 {
+                (self - rhs).magnitude_squared()
+            }
+            #[doc = "Linearly interpolates from the vector towards `rhs` by `t`, returning the vector at `t = 0` and `rhs` at `t = 1`."]
+            #[doc = ""]
+            #[doc = "`t` is not clamped, so values outside `[0, 1]` extrapolate along the line through both vectors."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([0.0; ", $n, "]);")]
+            #[doc = concat!("let b = ", stringify!($name), "::from_array([10.0; ", $n, "]).with_y(20.0);")]
+            #[doc = concat!("assert_eq!(a.lerp(b, 0.5), ", stringify!($name), "::from_array([5.0; ", $n, "]).with_y(10.0));")]
+            #[doc = concat!("assert_eq!(a.lerp(b, 2.0), ", stringify!($name), "::from_array([20.0; ", $n, "]).with_y(40.0));")]
+            #[doc = "```"]
+            #[inline]
+            #[must_use]
+            pub fn lerp(self, rhs: Self, t: f32) -> Self {
+                self + (rhs - self) * t
+            }
+            #[doc = "Restricts each component to the range given by the matching components of `min` and `max`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::splat(2.0).with_x(-2.0);")]
+            #[doc = concat!("let clamped = v.clamp(", stringify!($name), "::splat(-1.0), ", stringify!($name), "::ONE);")]
+            #[doc = concat!("assert_eq!(clamped, ", stringify!($name), "::ONE.with_x(-1.0));")]
+            #[doc = "```"]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "Panics if any component of `min` is greater than the matching component of `max`, or either is NaN."]
+            #[must_use]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                Self {
+                    $($c: self.$c.clamp(min.$c, max.$c)),+
+                }
+            }
+            #[doc = "Returns the normalised vector, or the zero vector if the magnitude is below `1e-6`."]
+            #[doc = ""]
+            #[doc = "Unlike `normal()`, this never produces NaN components from a zero-length vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::ZERO.normal_or_zero(), ", stringify!($name), "::ZERO);")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::splat(1e-7).normal_or_zero(), ", stringify!($name), "::ZERO);")]
+            #[doc = concat!("let v = ", stringify!($name), "::splat(2.0);")]
+            #[doc = "::approx::assert_ulps_eq!("]
+            #[doc = "    v.normal_or_zero().as_array().as_slice(),"]
+            #[doc = "    v.normal().as_array().as_slice()"]
+            #[doc = ");"]
+            #[doc = "```"]
+            #[must_use]
+            pub fn normal_or_zero(self) -> Self {
+                let m = self.magnitude();
+                if m < 1e-6 {
+                    Self::ZERO
+                } else {
+                    self / m
+                }
+            }
+            #[doc = "Returns the vector scaled down to a magnitude of `max` if it is longer, otherwise the vector unchanged."]
+            #[doc = ""]
+            #[doc = "The zero vector is returned unchanged."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::ZERO.with_x(3.0).with_y(4.0);")]
+            #[doc = concat!("::approx::assert_ulps_eq!(v.clamp_magnitude(2.5), ", stringify!($name), "::ZERO.with_x(1.5).with_y(2.0));")]
+            #[doc = "::approx::assert_ulps_eq!(v.clamp_magnitude(2.5).magnitude(), 2.5);"]
+            #[doc = ""]
+            #[doc = concat!("let v = ", stringify!($name), "::ZERO.with_x(6.0).with_y(8.0);")]
+            #[doc = "::approx::assert_ulps_eq!("]
+            #[doc = "    v.clamp_magnitude(5.0).as_array().as_slice(),"]
+            #[doc = "    (v.normal() * 5.0).as_array().as_slice()"]
+            #[doc = ");"]
+            #[doc = "::approx::assert_ulps_eq!(v.clamp_magnitude(1.0).magnitude(), 1.0);"]
+            #[doc = "::approx::assert_ulps_eq!("]
+            #[doc = "    v.clamp_magnitude(1.0).normal().as_array().as_slice(),"]
+            #[doc = "    v.normal().as_array().as_slice()"]
+            #[doc = ");"]
+            #[doc = "assert_eq!(v.clamp_magnitude(100.0), v);"]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::default().clamp_magnitude(1.0), ", stringify!($name), "::default());")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn clamp_magnitude(self, max: f32) -> Self {
+                let m = self.magnitude();
+                if m <= max || m == 0.0 {
+                    self
+                } else {
+                    self * (max / m)
+                }
+            }
+            #[doc = "Returns true if every component differs from the matching component of `other` by at most `epsilon`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let a = ", stringify!($name), "::from_array([1.0; ", $n, "]);")]
+            #[doc = "assert!(a.approx_eq(a + 1e-7, 1e-6));"]
+            #[doc = "assert!(!a.approx_eq(a + 1e-3, 1e-6));"]
+            #[doc = "```"]
+            #[must_use]
+            pub fn approx_eq(self, other: Self, epsilon: f32) -> bool {
+                $((self.$c - other.$c).abs() <= epsilon)&&+
+            }
+            #[doc = "Returns true if every component of the vector is finite, neither infinite nor NaN."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let mut v = ", stringify!($name), "::from_array([1.0; ", $n, "]);")]
+            #[doc = "assert!(v.is_finite());"]
+            #[doc = "v.x = f32::INFINITY;"]
+            #[doc = "assert!(!v.is_finite());"]
+            #[doc = "v.x = f32::NAN;"]
+            #[doc = "assert!(!v.is_finite());"]
+            #[doc = "```"]
+            #[must_use]
+            pub fn is_finite(self) -> bool {
+                $(self.$c.is_finite())&&+
+            }
+            #[doc = "Returns true if any component of the vector is NaN."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let mut v = ", stringify!($name), "::from_array([f32::INFINITY; ", $n, "]);")]
+            #[doc = "assert!(!v.is_nan());"]
+            #[doc = "v.x = f32::NAN;"]
+            #[doc = "assert!(v.is_nan());"]
+            #[doc = "```"]
+            #[must_use]
+            pub fn is_nan(self) -> bool {
+                $(self.$c.is_nan())||+
+            }
+            #[doc = "Returns the vector with the absolute value of each component."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::splat(-1.5).with_x(2.0);")]
+            #[doc = concat!("assert_eq!(v.abs(), ", stringify!($name), "::splat(1.5).with_x(2.0));")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn abs(self) -> Self {
+                Self {
+                    $($c: self.$c.abs()),+
+                }
+            }
+            #[doc = "Returns the vector with each component rounded down to an integer."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::splat(-1.5).with_x(2.5);")]
+            #[doc = concat!("assert_eq!(v.floor(), ", stringify!($name), "::splat(-2.0).with_x(2.0));")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn floor(self) -> Self {
+                Self {
+                    $($c: $crate::float::floor(self.$c)),+
+                }
+            }
+            #[doc = "Returns the vector with each component rounded up to an integer."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::splat(-1.5).with_x(2.5);")]
+            #[doc = concat!("assert_eq!(v.ceil(), ", stringify!($name), "::splat(-1.0).with_x(3.0));")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn ceil(self) -> Self {
+                Self {
+                    $($c: $crate::float::ceil(self.$c)),+
+                }
+            }
+            #[doc = "Returns the vector with each component rounded to the nearest integer, with halfway cases rounded away from zero."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::splat(-1.6).with_x(2.4).with_y(0.5);")]
+            #[doc = concat!("assert_eq!(v.round(), ", stringify!($name), "::splat(-2.0).with_x(2.0).with_y(1.0));")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn round(self) -> Self {
+                Self {
+                    $($c: $crate::float::round(self.$c)),+
+                }
+            }
+            #[doc = "Returns the smaller of each component of the vector and the matching component of `rhs`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let a = ", stringify!($name), "::splat(1.0).with_x(5.0);")]
+            #[doc = concat!("let b = ", stringify!($name), "::splat(3.0);")]
+            #[doc = concat!("assert_eq!(a.min(b), ", stringify!($name), "::splat(1.0).with_x(3.0));")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                Self {
+                    $($c: self.$c.min(rhs.$c)),+
+                }
+            }
+            #[doc = "Returns the larger of each component of the vector and the matching component of `rhs`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let a = ", stringify!($name), "::splat(1.0).with_x(5.0);")]
+            #[doc = concat!("let b = ", stringify!($name), "::splat(3.0);")]
+            #[doc = concat!("assert_eq!(a.max(b), ", stringify!($name), "::splat(3.0).with_x(5.0));")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                Self {
+                    $($c: self.$c.max(rhs.$c)),+
+                }
+            }
+            #[doc = "Returns the smallest component of the vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::splat(3.0).with_y(-1.0).min_element(), -1.0);")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn min_element(self) -> f32 {
+                self.fold(f32::INFINITY, f32::min)
+            }
+            #[doc = "Returns the largest component of the vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::splat(3.0).with_y(5.0).max_element(), 5.0);")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn max_element(self) -> f32 {
+                self.fold(f32::NEG_INFINITY, f32::max)
+            }
+            #[doc = "Returns the vector mirrored about the surface with the given unit `normal`, as for a reflected ray."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::X - ", stringify!($name), "::Y;")]
+            #[doc = concat!("assert_eq!(v.reflect(", stringify!($name), "::Y), ", stringify!($name), "::X + ", stringify!($name), "::Y);")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn reflect(self, normal: Self) -> Self {
+                self - normal * (2.0 * self.dot(normal))
+            }
+            #[doc = "Returns the projection of the vector onto `rhs`, the part of the vector parallel to `rhs`."]
+            #[doc = ""]
+            #[doc = "Every component is NaN if `rhs` is the zero vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::X * 3.0 + ", stringify!($name), "::Y * 4.0;")]
+            #[doc = concat!("assert_eq!(v.project_onto(", stringify!($name), "::X * 2.0), ", stringify!($name), "::X * 3.0);")]
+            #[doc = concat!("assert!(v.project_onto(", stringify!($name), "::ZERO).is_nan());")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn project_onto(self, rhs: Self) -> Self {
+                rhs * (self.dot(rhs) / rhs.dot(rhs))
+            }
+            #[doc = "Returns the rejection of the vector from `rhs`, the part of the vector perpendicular to `rhs`."]
+            #[doc = ""]
+            #[doc = "Every component is NaN if `rhs` is the zero vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::X * 3.0 + ", stringify!($name), "::Y * 4.0;")]
+            #[doc = concat!("assert_eq!(v.reject_from(", stringify!($name), "::X * 2.0), ", stringify!($name), "::Y * 4.0);")]
+            #[doc = concat!("assert!(v.reject_from(", stringify!($name), "::ZERO).is_nan());")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn reject_from(self, rhs: Self) -> Self {
+                self - self.project_onto(rhs)
+            }
+            #[doc = "Returns the angle in radians between the vector and `rhs`, from 0 to π."]
+            #[doc = ""]
+            #[doc = "The cosine is clamped to `[-1, 1]` before taking its arccosine, so rounding error cannot produce NaN for nearly parallel vectors. The result is NaN if either vector is the zero vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = "use ::core::f32::consts::FRAC_PI_2;"]
+            #[doc = concat!("let (x, y) = (", stringify!($name), "::X, ", stringify!($name), "::Y);")]
+            #[doc = "::approx::assert_ulps_eq!(x.angle_between(y), FRAC_PI_2);"]
+            #[doc = "assert_eq!((x * 2.0).angle_between(x), 0.0);"]
+            #[doc = concat!("assert!(x.angle_between(", stringify!($name), "::ZERO).is_nan());")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn angle_between(self, rhs: Self) -> f32 {
+                let cos = self.dot(rhs) / (self.magnitude() * rhs.magnitude());
+                $crate::float::acos(cos.clamp(-1.0, 1.0))
+            }
+            #[doc = "Returns the vector with `f` applied to each component."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array([3.0; ", $n, "]).map(|c| c * c);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([9.0; ", $n, "]));")]
+            #[doc = concat!("let v = ", stringify!($name), "::splat(9.0).with_x(4.0).map(f32::sqrt);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::splat(3.0).with_x(2.0));")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn map(self, f: impl Fn(f32) -> f32) -> Self {
+                Self {
+                    $($c: f(self.$c)),+
+                }
+            }
+            #[doc = "Combines the components in order, starting from `init`, by repeatedly applying `f`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array([2.0; ", $n, "]);")]
+            #[doc = concat!("assert_eq!(v.fold(0.0, |sum, c| sum + c), ", $n, ".0 * 2.0);")]
+            #[doc = "```"]
+            #[must_use]
+            pub fn fold(self, init: f32, f: impl Fn(f32, f32) -> f32) -> f32 {
+                let acc = init;
+                $(let acc = f(acc, self.$c);)+
+                acc
+            }
+        }
+        impl ::core::fmt::Display for $name {
+            #[doc = "Formats the vector as a tuple of its components, such as `(1.0, 2.0)`. Precision is applied to every component."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::ONE.with_x(2.5);")]
+            #[doc = concat!("assert_eq!(format!(\"{v}\"), format!(\"(2.5, {})\", [\"1.0\"; ", $n, " - 1].join(\", \")));")]
+            #[doc = concat!("assert_eq!(format!(\"{v:.2}\"), format!(\"(2.50, {})\", [\"1.00\"; ", $n, " - 1].join(\", \")));")]
+            #[doc = "```"]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str("(")?;
+                for (i, c) in self.as_array().into_iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    match f.precision() {
+                        Some(precision) => write!(f, "{c:.precision$}")?,
+                        None => write!(f, "{c:?}")?,
+                    }
+                }
+                f.write_str(")")
+            }
+        }
+        impl ::core::ops::Add for $name {
+            type Output = Self;
+            #[doc = "Adds each component of `rhs` to the matching component of the vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array([1.0; ", $n, "])")]
+            #[doc = concat!("    + ", stringify!($name), "::from_array([2.0; ", $n, "]);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([3.0; ", $n, "]));")]
+            #[doc = "```"]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    $($c: self.$c + rhs.$c),+
+                }
+            }
+        }
+        impl ::core::ops::AddAssign for $name {
+            #[doc = "Adds each component of `rhs` to the matching component of the vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let mut v = ", stringify!($name), "::from_array([1.0; ", $n, "]);")]
+            #[doc = concat!("v += ", stringify!($name), "::from_array([2.0; ", $n, "]);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([3.0; ", $n, "]));")]
+            #[doc = "```"]
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$c += rhs.$c;)+
+            }
+        }
+        impl ::core::ops::Sub for $name {
+            type Output = Self;
+            #[doc = "Subtracts each component of `rhs` from the matching component of the vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array([1.0; ", $n, "])")]
+            #[doc = concat!("    - ", stringify!($name), "::from_array([2.0; ", $n, "]);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([-1.0; ", $n, "]));")]
+            #[doc = "```"]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self {
+                    $($c: self.$c - rhs.$c),+
+                }
+            }
+        }
+        impl ::core::ops::SubAssign for $name {
+            #[doc = "Subtracts each component of `rhs` from the matching component of the vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let mut v = ", stringify!($name), "::from_array([1.0; ", $n, "]);")]
+            #[doc = concat!("v -= ", stringify!($name), "::from_array([2.0; ", $n, "]);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([-1.0; ", $n, "]));")]
+            #[doc = "```"]
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$c -= rhs.$c;)+
+            }
+        }
+        impl ::core::ops::Neg for $name {
+            type Output = Self;
+            #[doc = "Negates each component of the vector."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = -", stringify!($name), "::from_array([1.0; ", $n, "]).with_x(-2.0);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([-1.0; ", $n, "]).with_x(2.0));")]
+            #[doc = "```"]
+            fn neg(self) -> Self::Output {
+                Self {
+                    $($c: -self.$c),+
+                }
+            }
+        }
+        impl ::core::ops::Mul for $name {
+            type Output = Self;
+            #[doc = "Multiplies each component of the vector by the matching component of `rhs`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array([2.0; ", $n, "])")]
+            #[doc = concat!("    * ", stringify!($name), "::from_array([3.0; ", $n, "]).with_x(4.0);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([6.0; ", $n, "]).with_x(8.0));")]
+            #[doc = "```"]
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self {
+                    $($c: self.$c * rhs.$c),+
+                }
+            }
+        }
+        impl ::core::ops::MulAssign for $name {
+            #[doc = "Multiplies each component of the vector by the matching component of `rhs`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let mut v = ", stringify!($name), "::from_array([2.0; ", $n, "]);")]
+            #[doc = concat!("v *= ", stringify!($name), "::from_array([3.0; ", $n, "]).with_x(4.0);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([6.0; ", $n, "]).with_x(8.0));")]
+            #[doc = "```"]
+            fn mul_assign(&mut self, rhs: Self) {
+                $(self.$c *= rhs.$c;)+
+            }
+        }
+        impl ::core::ops::Div for $name {
+            type Output = Self;
+            #[doc = "Divides each component of the vector by the matching component of `rhs`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array([6.0; ", $n, "])")]
+            #[doc = concat!("    / ", stringify!($name), "::from_array([3.0; ", $n, "]).with_x(4.0);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([2.0; ", $n, "]).with_x(1.5));")]
+            #[doc = "```"]
+            fn div(self, rhs: Self) -> Self::Output {
+                Self {
+                    $($c: self.$c / rhs.$c),+
+                }
+            }
+        }
+        impl ::core::ops::DivAssign for $name {
+            #[doc = "Divides each component of the vector by the matching component of `rhs`."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let mut v = ", stringify!($name), "::from_array([6.0; ", $n, "]);")]
+            #[doc = concat!("v /= ", stringify!($name), "::from_array([3.0; ", $n, "]).with_x(4.0);")]
+            #[doc = concat!("assert_eq!(v, ", stringify!($name), "::from_array([2.0; ", $n, "]).with_x(1.5));")]
+            #[doc = "```"]
+            fn div_assign(&mut self, rhs: Self) {
+                $(self.$c /= rhs.$c;)+
+            }
+        }
+        impl ::core::ops::Index<usize> for $name {
+            type Output = f32;
+            #[doc = "Returns the component at `index`, counting from `x` at 0."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = ", stringify!($name), "::from_array(::core::array::from_fn(|i| i as f32));")]
+            #[doc = concat!("for i in 0..", $n, " {")]
+            #[doc = "    assert_eq!(v[i], i as f32);"]
+            #[doc = "}"]
+            #[doc = "```"]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "Panics if `index` is not less than the number of components."]
+            #[doc = "```should_panic"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let x = ", stringify!($name), "::default()[", $n, "];")]
+            #[doc = "```"]
+            fn index(&self, index: usize) -> &Self::Output {
+                [$(&self.$c),+].into_iter().nth(index).unwrap_or_else(|| {
+                    panic!(concat!(stringify!($name), " index {} out of range 0..", $n), index)
+                })
+            }
+        }
+        impl ::core::ops::IndexMut<usize> for $name {
+            #[doc = "Returns a mutable reference to the component at `index`, counting from `x` at 0."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let mut v = ", stringify!($name), "::default();")]
+            #[doc = "v[1] = 2.0;"]
+            #[doc = "assert_eq!(v.y, 2.0);"]
+            #[doc = "```"]
+            #[doc = ""]
+            #[doc = "# Panics"]
+            #[doc = "Panics if `index` is not less than the number of components."]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                [$(&mut self.$c),+].into_iter().nth(index).unwrap_or_else(|| {
+                    panic!(concat!(stringify!($name), " index {} out of range 0..", $n), index)
+                })
+            }
+        }
+        impl ::core::iter::Sum for $name {
+            #[doc = "Returns the component-wise sum of the vectors, or the zero vector if there are none."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = [", stringify!($name), "::from_array([1.0; ", $n, "]); 3];")]
+            #[doc = concat!("assert_eq!(v.into_iter().sum::<", stringify!($name), ">(), ", stringify!($name), "::from_array([3.0; ", $n, "]));")]
+            #[doc = "```"]
+            #[doc = ""]
+            #[doc = "The centroid of a set of points is their sum divided by how many there are."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let points = [", stringify!($name), "::ZERO, ", stringify!($name), "::X * 3.0, ", stringify!($name), "::Y * 3.0];")]
+            #[doc = concat!("let centroid = points.iter().copied().sum::<", stringify!($name), ">() / 3.0;")]
+            #[doc = concat!("assert_eq!(centroid, ", stringify!($name), "::X + ", stringify!($name), "::Y);")]
+            #[doc = "```"]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |sum, v| sum + v)
+            }
+        }
+        impl<'a> ::core::iter::Sum<&'a $name> for $name {
+            #[doc = "Returns the component-wise sum of the vectors, or the zero vector if there are none."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = [", stringify!($name), "::from_array([1.0; ", $n, "]); 3];")]
+            #[doc = concat!("assert_eq!(v.iter().sum::<", stringify!($name), ">(), ", stringify!($name), "::from_array([3.0; ", $n, "]));")]
+            #[doc = "```"]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+        impl ::core::iter::Product for $name {
+            #[doc = "Returns the component-wise product of the vectors, or a vector of ones if there are none."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = [", stringify!($name), "::from_array([2.0; ", $n, "]); 3];")]
+            #[doc = concat!("assert_eq!(v.into_iter().product::<", stringify!($name), ">(), ", stringify!($name), "::from_array([8.0; ", $n, "]));")]
+            #[doc = concat!("assert_eq!(::core::iter::empty::<", stringify!($name), ">().product::<", stringify!($name), ">(), ", stringify!($name), "::ONE);")]
+            #[doc = "```"]
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |product, v| product * v)
+            }
+        }
+        impl<'a> ::core::iter::Product<&'a $name> for $name {
+            #[doc = "Returns the component-wise product of the vectors, or a vector of ones if there are none."]
+            #[doc = "```"]
+            #[doc = "# use ::maths::prelude::*;"]
+            #[doc = concat!("let v = [", stringify!($name), "::from_array([2.0; ", $n, "]); 3];")]
+            #[doc = concat!("assert_eq!(v.iter().product::<", stringify!($name), ">(), ", stringify!($name), "::from_array([8.0; ", $n, "]));")]
+            #[doc = "```"]
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+    };
+}
@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::float::{cos, sin, sqrt};
+use crate::Vector3;
 
 /// 2-dimensional vector.
 /// ```
@@ -7,7 +10,20 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 /// assert_eq!(pos.x, 1.0);
 /// assert_eq!(pos.y, 2.0);
 /// ```
+///
+/// The vector is `#[repr(C)]`, laid out as its components in order with no
+/// padding, so it can be shared with C or uploaded to the GPU as is.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::core::mem::{align_of, offset_of, size_of};
+/// assert_eq!(size_of::<Vector2>(), 8);
+/// assert_eq!(align_of::<Vector2>(), 4);
+/// assert_eq!(offset_of!(Vector2, x), 0);
+/// assert_eq!(offset_of!(Vector2, y), 4);
+/// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[repr(C)]
 pub struct Vector2 {
     pub x: f32,
     pub y: f32,
@@ -59,6 +75,32 @@ impl Vector2 {
     pub const fn from_tuple((x, y): (f32, f32)) -> Self {
         Self { x, y }
     }
+    /// Returns the vector with `x` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector2::new(1.0, 2.0).with_x(0.0),
+    ///     Vector2::new(0.0, 2.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_x(self, x: f32) -> Self {
+        Self { x, ..self }
+    }
+    /// Returns the vector with `y` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector2::new(1.0, 2.0).with_y(0.0),
+    ///     Vector2::new(1.0, 0.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_y(self, y: f32) -> Self {
+        Self { y, ..self }
+    }
     /// Returns the magnitude of the vector, also known as the length.
     /// ```
     /// # use ::maths::prelude::*;
@@ -69,7 +111,7 @@ impl Vector2 {
     /// ```
     #[must_use]
     pub fn magnitude(self) -> f32 {
-        (self.x.powi(2) + self.y.powi(2)).sqrt()
+        sqrt(self.magnitude_squared())
     }
     /// Returns the normalised vector, also known as the unit vector.
     /// ```
@@ -103,7 +145,90 @@ impl Vector2 {
     pub fn dot(self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y
     }
+    /// Returns the vector rotated a quarter turn counter-clockwise in a y-up
+    /// system.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Vector2::new(2.0, 1.0).perp(), Vector2::new(-1.0, 2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+    /// Returns the perp dot product of the vector, also known as the 2D cross
+    /// product.
+    ///
+    /// This is positive when `rhs` is counter-clockwise of the vector in a
+    /// y-up system, negative when it is clockwise and zero when they are
+    /// parallel.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let v = Vector2::new(1.0, 0.0);
+    /// assert_eq!(v.perp_dot(Vector2::new(1.0, 2.0)), 2.0);
+    /// assert_eq!(v.perp_dot(Vector2::new(1.0, -2.0)), -2.0);
+    /// assert_eq!(v.perp_dot(Vector2::new(3.0, 0.0)), 0.0);
+    /// ```
+    #[must_use]
+    pub fn perp_dot(self, rhs: Self) -> f32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+    /// Returns the vector extended with a `z` component.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector2::new(1.0, 2.0).extend(3.0),
+    ///     Vector3::new(1.0, 2.0, 3.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn extend(self, z: f32) -> Vector3 {
+        Vector3::new(self.x, self.y, z)
+    }
+    /// Returns the unit vector `(cos, sin)` pointing at `radians`, measured
+    /// counter-clockwise from the positive x axis in a y-up system.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Vector2::from_angle(0.0), Vector2::new(1.0, 0.0));
+    /// let v = Vector2::from_angle(::std::f32::consts::FRAC_PI_4);
+    /// ::approx::assert_ulps_eq!(
+    ///     v.as_array().as_slice(),
+    ///     [::std::f32::consts::FRAC_1_SQRT_2; 2].as_slice()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_angle(radians: f32) -> Self {
+        Self {
+            x: cos(radians),
+            y: sin(radians),
+        }
+    }
+    /// Returns the vector rotated by `radians`. Positive angles rotate
+    /// counter-clockwise in a y-up system (clockwise when y points down, as in
+    /// screen space).
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let v = Vector2::new(1.0, 0.0).rotate(::std::f32::consts::PI / 2.0);
+    /// ::approx::assert_ulps_eq!(
+    ///     v.as_array().as_slice(),
+    ///     [0.0, 1.0].as_slice()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn rotate(self, radians: f32) -> Self {
+        let (s, c) = (sin(radians), cos(radians));
+        Self {
+            x: self.x * c - self.y * s,
+            y: self.x * s + self.y * c,
+        }
+    }
 }
+vector_impl!(Vector2 { x: X, y: Y }, 2);
+
 impl From<Vector2> for [f32; 2] {
     /// See [`Vector2::as_array()`].
     fn from(value: Vector2) -> Self {
@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::float::sqrt;
+use crate::{Vector2, Vector4};
 
 /// 3-dimensional vector.
 /// ```
@@ -8,7 +11,21 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 /// assert_eq!(pos.y, 2.0);
 /// assert_eq!(pos.z, 3.0);
 /// ```
+///
+/// The vector is `#[repr(C)]`, laid out as its components in order with no
+/// padding, so it can be shared with C or uploaded to the GPU as is.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::core::mem::{align_of, offset_of, size_of};
+/// assert_eq!(size_of::<Vector3>(), 12);
+/// assert_eq!(align_of::<Vector3>(), 4);
+/// assert_eq!(offset_of!(Vector3, x), 0);
+/// assert_eq!(offset_of!(Vector3, y), 4);
+/// assert_eq!(offset_of!(Vector3, z), 8);
+/// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[repr(C)]
 pub struct Vector3 {
     pub x: f32,
     pub y: f32,
@@ -73,6 +90,45 @@ impl Vector3 {
     pub const fn from_tuple((x, y, z): (f32, f32, f32)) -> Self {
         Self { x, y, z }
     }
+    /// Returns the vector with `x` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector3::new(1.0, 2.0, 3.0).with_x(0.0),
+    ///     Vector3::new(0.0, 2.0, 3.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_x(self, x: f32) -> Self {
+        Self { x, ..self }
+    }
+    /// Returns the vector with `y` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector3::new(1.0, 2.0, 3.0).with_y(0.0),
+    ///     Vector3::new(1.0, 0.0, 3.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_y(self, y: f32) -> Self {
+        Self { y, ..self }
+    }
+    /// Returns the vector with `z` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector3::new(1.0, 2.0, 3.0).with_z(0.0),
+    ///     Vector3::new(1.0, 2.0, 0.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_z(self, z: f32) -> Self {
+        Self { z, ..self }
+    }
     /// Returns the magnitude of the vector, also known as the length.
     /// ```
     /// # use ::maths::prelude::*;
@@ -83,17 +139,14 @@ impl Vector3 {
     /// ```
     #[must_use]
     pub fn magnitude(self) -> f32 {
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
+        sqrt(self.magnitude_squared())
     }
     /// Returns the normalised vector, also known as the unit vector.
     /// ```
     /// # use ::maths::prelude::*;
     /// let normal = Vector3::new(0.0, 3.0, 4.0).normal();
     /// let expected = Vector3::new(0.0, 0.6, 0.8);
-    /// ::approx::assert_ulps_eq!(
-    ///     normal.as_array().as_slice(),
-    ///     normal.as_array().as_slice()
-    /// );
+    /// ::approx::assert_ulps_eq!(normal, expected);
     /// ```
     #[must_use]
     pub fn normal(self) -> Self {
@@ -118,7 +171,91 @@ impl Vector3 {
     pub fn dot(self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
     }
+    /// Returns the cross product of the vector, also known as the vector
+    /// product.
+    ///
+    /// The result is perpendicular to both vectors and follows the
+    /// right-hand rule.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(Vector3::X.cross(Vector3::Y), Vector3::Z);
+    /// assert_eq!(Vector3::Y.cross(Vector3::X), -Vector3::Z);
+    ///
+    /// let v = Vector3::new(1.0, 0.0, 0.0).cross(Vector3::new(0.0, 1.0, 0.0));
+    /// ::approx::assert_ulps_eq!(
+    ///     v.as_array().as_slice(),
+    ///     [0.0, 0.0, 1.0].as_slice()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+    /// Returns the vector with `z` dropped.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector3::new(1.0, 2.0, 3.0).truncate(),
+    ///     Vector2::new(1.0, 2.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate(self) -> Vector2 {
+        Vector2::new(self.x, self.y)
+    }
+    /// Returns the vector extended with a `w` component, such as 1 for a
+    /// point or 0 for a direction in homogeneous coordinates.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector3::new(1.0, 2.0, 3.0).extend(1.0),
+    ///     Vector4::new(1.0, 2.0, 3.0, 1.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn extend(self, w: f32) -> Vector4 {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+    /// Returns the direction of the vector after refracting through a surface
+    /// with the given `normal`, following Snell's law.
+    ///
+    /// The vector is the unit incident direction pointing into the surface and
+    /// `normal` is the unit surface normal pointing out of it, against the
+    /// incident direction. `eta` is the ratio of the refractive index being
+    /// left to the one being entered. Returns `None` on total internal
+    /// reflection.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// let normal = Vector3::new(0.0, 1.0, 0.0);
+    /// let incident = Vector3::new(1.0, -1.0, 0.0).normal();
+    /// let refracted = incident.refract(normal, 1.0).unwrap();
+    /// ::approx::assert_ulps_eq!(
+    ///     refracted.as_array().as_slice(),
+    ///     incident.as_array().as_slice()
+    /// );
+    ///
+    /// let grazing = Vector3::new(1.0, -0.2, 0.0).normal();
+    /// assert_eq!(grazing.refract(normal, 1.5), None);
+    /// ```
+    #[must_use]
+    pub fn refract(self, normal: Self, eta: f32) -> Option<Self> {
+        let cos_i = -self.dot(normal);
+        let sin2_t = eta * eta * (1.0 - cos_i * cos_i);
+        if sin2_t > 1.0 {
+            return None;
+        }
+        let cos_t = sqrt(1.0 - sin2_t);
+        Some(self * eta + normal * (eta * cos_i - cos_t))
+    }
 }
+vector_impl!(Vector3 { x: X, y: Y, z: Z }, 3);
+
 impl From<Vector3> for [f32; 3] {
     /// See [`Vector3::as_array()`].
     fn from(value: Vector3) -> Self {
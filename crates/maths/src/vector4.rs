@@ -1,4 +1,7 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::float::sqrt;
+use crate::Vector3;
 
 /// 4-dimensional vector.
 /// ```
@@ -9,7 +12,22 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 /// assert_eq!(pos.z, 3.0);
 /// assert_eq!(pos.w, 4.0);
 /// ```
+///
+/// The vector is `#[repr(C)]`, laid out as its components in order with no
+/// padding, so it can be shared with C or uploaded to the GPU as is.
+/// ```
+/// # use ::maths::prelude::*;
+/// use ::core::mem::{align_of, offset_of, size_of};
+/// assert_eq!(size_of::<Vector4>(), 16);
+/// assert_eq!(align_of::<Vector4>(), 4);
+/// assert_eq!(offset_of!(Vector4, x), 0);
+/// assert_eq!(offset_of!(Vector4, y), 4);
+/// assert_eq!(offset_of!(Vector4, z), 8);
+/// assert_eq!(offset_of!(Vector4, w), 12);
+/// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[repr(C)]
 pub struct Vector4 {
     pub x: f32,
     pub y: f32,
@@ -75,6 +93,58 @@ impl Vector4 {
     pub const fn from_tuple((x, y, z, w): (f32, f32, f32, f32)) -> Self {
         Self { x, y, z, w }
     }
+    /// Returns the vector with `x` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector4::new(1.0, 2.0, 3.0, 4.0).with_x(0.0),
+    ///     Vector4::new(0.0, 2.0, 3.0, 4.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_x(self, x: f32) -> Self {
+        Self { x, ..self }
+    }
+    /// Returns the vector with `y` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector4::new(1.0, 2.0, 3.0, 4.0).with_y(0.0),
+    ///     Vector4::new(1.0, 0.0, 3.0, 4.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_y(self, y: f32) -> Self {
+        Self { y, ..self }
+    }
+    /// Returns the vector with `z` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector4::new(1.0, 2.0, 3.0, 4.0).with_z(0.0),
+    ///     Vector4::new(1.0, 2.0, 0.0, 4.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_z(self, z: f32) -> Self {
+        Self { z, ..self }
+    }
+    /// Returns the vector with `w` replaced.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector4::new(1.0, 2.0, 3.0, 4.0).with_w(0.0),
+    ///     Vector4::new(1.0, 2.0, 3.0, 0.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_w(self, w: f32) -> Self {
+        Self { w, ..self }
+    }
     /// Returns the magnitude of the vector, also known as the length.
     /// ```
     /// # use ::maths::prelude::*;
@@ -85,7 +155,7 @@ impl Vector4 {
     /// ```
     #[must_use]
     pub fn magnitude(self) -> f32 {
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2) + self.w.powi(2)).sqrt()
+        sqrt(self.magnitude_squared())
     }
     /// Returns the normalised vector, also known as the unit vector.
     /// ```
@@ -121,7 +191,30 @@ impl Vector4 {
     pub fn dot(self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
+    /// Returns the vector with `w` dropped.
+    /// ```
+    /// # use ::maths::prelude::*;
+    /// assert_eq!(
+    ///     Vector4::new(1.0, 2.0, 3.0, 4.0).truncate(),
+    ///     Vector3::new(1.0, 2.0, 3.0)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate(self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
+    }
 }
+vector_impl!(
+    Vector4 {
+        x: X,
+        y: Y,
+        z: Z,
+        w: W
+    },
+    4
+);
+
 impl From<Vector4> for [f32; 4] {
     /// See [`Vector4::as_array()`].
     fn from(value: Vector4) -> Self {